@@ -0,0 +1,78 @@
+use std::fmt::Display;
+
+use console::{Key, Term};
+
+use crate::error::{Error, Result};
+use crate::prompt::Prompt;
+use crate::theme::{ClackTheme, Theme, ThemeState};
+
+/// An acknowledgement prompt: shows a message and blocks until the user
+/// presses `Enter` to continue.
+///
+/// Useful for warnings or errors that need to be seen before the program
+/// moves on, where [`confirm()`](crate::confirm) would be semantically
+/// wrong since there's no yes/no to answer.
+///
+/// Constructed with [`alert()`](crate::alert) or [`Alert::new`].
+pub struct Alert {
+    prompt: String,
+    message: String,
+    on_cancel: Option<Box<dyn Fn()>>,
+}
+
+impl Alert {
+    /// Creates a new alert with the given prompt line.
+    pub fn new(prompt: impl Display) -> Self {
+        Self {
+            prompt: prompt.to_string(),
+            message: String::new(),
+            on_cancel: None,
+        }
+    }
+
+    /// Sets the body text shown below the prompt line.
+    pub fn message(mut self, message: impl Display) -> Self {
+        self.message = message.to_string();
+        self
+    }
+
+    /// Registers a callback run when the user cancels with `Esc`, before
+    /// [`Error::Cancelled`] is returned.
+    pub fn on_cancel(mut self, on_cancel: impl Fn() + 'static) -> Self {
+        self.on_cancel = Some(Box::new(on_cancel));
+        self
+    }
+
+    /// Runs the prompt on the crate-wide default terminal, blocking until
+    /// the user presses `Enter`.
+    pub fn interact(&mut self) -> Result<()> {
+        self.interact_on(&crate::prompt::default_term())
+    }
+
+    /// Runs the prompt on the given terminal, blocking until the user
+    /// presses `Enter`.
+    pub fn interact_on(&mut self, term: &Term) -> Result<()> {
+        let mut prompt = Prompt::on(term);
+
+        prompt.render(&ClackTheme.format_alert(&self.prompt, &self.message, ThemeState::Active))?;
+
+        let acknowledged = loop {
+            match prompt.read_key()? {
+                Key::Enter => break true,
+                Key::Escape => break false,
+                _ => continue,
+            }
+        };
+
+        if !acknowledged {
+            prompt.finish(&ClackTheme.format_alert(&self.prompt, &self.message, ThemeState::Cancel))?;
+            if let Some(on_cancel) = &self.on_cancel {
+                on_cancel();
+            }
+            return Err(Error::Cancelled);
+        }
+
+        prompt.finish(&ClackTheme.format_alert(&self.prompt, &self.message, ThemeState::Submit))?;
+        Ok(())
+    }
+}
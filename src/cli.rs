@@ -0,0 +1,430 @@
+use std::collections::VecDeque;
+use std::fmt::Display;
+use std::io;
+
+use crate::error::Result;
+use crate::Spinner;
+
+/// The crate's interactive surface, extracted as a trait so that code built
+/// on cliclack can swap in [`MockCli`] under test instead of driving a real
+/// terminal.
+///
+/// [`RealCli`] forwards every method to the matching free function
+/// (`crate::confirm`, `crate::input`, ...); downstream code should be
+/// written against `impl Cli` (or a generic `C: Cli`) rather than calling
+/// the free functions directly wherever it wants to be testable.
+///
+/// Every method takes `&dyn Display` rather than `impl Display`, so the
+/// trait is dyn-compatible and a single `&mut dyn Cli` call boundary works
+/// without making every caller generic:
+///
+/// ```
+/// use cliclack::{Cli, MockCli};
+///
+/// fn run(cli: &mut dyn Cli) -> cliclack::Result<()> {
+///     cli.intro(&"demo")?;
+///     let name = cli.input(&"name")?;
+///     cli.outro(&name)?;
+///     Ok(())
+/// }
+///
+/// let mut mock = MockCli::new();
+/// mock.expect_input("Alice");
+/// run(&mut mock).unwrap();
+/// assert_eq!(mock.messages(), ["demo", "Alice"]);
+/// ```
+pub trait Cli {
+    /// See [`crate::intro`].
+    fn intro(&mut self, title: &dyn Display) -> io::Result<()>;
+    /// See [`crate::outro`].
+    fn outro(&mut self, message: &dyn Display) -> io::Result<()>;
+    /// See [`crate::note`].
+    fn note(&mut self, prompt: &dyn Display, message: &dyn Display) -> io::Result<()>;
+    /// See [`crate::confirm`].
+    fn confirm(&mut self, prompt: &dyn Display) -> Result<bool>;
+    /// See [`crate::input`].
+    fn input(&mut self, prompt: &dyn Display) -> Result<String>;
+    /// See [`crate::password`].
+    fn password(&mut self, prompt: &dyn Display) -> Result<String>;
+    /// See [`crate::select`]. `items` are the labels offered to the user.
+    fn select(&mut self, prompt: &dyn Display, items: &[&str]) -> Result<String>;
+    /// See [`crate::multiselect`]. `items` are the labels offered to the user.
+    fn multiselect(&mut self, prompt: &dyn Display, items: &[&str]) -> Result<Vec<String>>;
+    /// See [`crate::spinner`].
+    fn spinner(&mut self) -> Spinner;
+    /// See [`crate::log::remark`].
+    fn log_remark(&mut self, text: &dyn Display) -> io::Result<()>;
+    /// See [`crate::log::info`].
+    fn log_info(&mut self, text: &dyn Display) -> io::Result<()>;
+    /// See [`crate::log::warning`].
+    fn log_warning(&mut self, text: &dyn Display) -> io::Result<()>;
+    /// See [`crate::log::error`].
+    fn log_error(&mut self, text: &dyn Display) -> io::Result<()>;
+    /// See [`crate::log::success`].
+    fn log_success(&mut self, text: &dyn Display) -> io::Result<()>;
+    /// See [`crate::log::step`].
+    fn log_step(&mut self, text: &dyn Display) -> io::Result<()>;
+}
+
+/// The real [`Cli`] implementation, writing to and reading from an actual
+/// terminal via the crate's free functions.
+#[derive(Default)]
+pub struct RealCli;
+
+impl Cli for RealCli {
+    fn intro(&mut self, title: &dyn Display) -> io::Result<()> {
+        crate::intro(title)
+    }
+
+    fn outro(&mut self, message: &dyn Display) -> io::Result<()> {
+        crate::outro(message)
+    }
+
+    fn note(&mut self, prompt: &dyn Display, message: &dyn Display) -> io::Result<()> {
+        crate::note(prompt, message)
+    }
+
+    fn confirm(&mut self, prompt: &dyn Display) -> Result<bool> {
+        crate::confirm(prompt).interact()
+    }
+
+    fn input(&mut self, prompt: &dyn Display) -> Result<String> {
+        crate::input(prompt).interact()
+    }
+
+    fn password(&mut self, prompt: &dyn Display) -> Result<String> {
+        crate::password(prompt).interact()
+    }
+
+    fn select(&mut self, prompt: &dyn Display, items: &[&str]) -> Result<String> {
+        let mut select = crate::select(prompt);
+        for item in items {
+            select = select.item(item.to_string(), *item, "");
+        }
+        select.interact()
+    }
+
+    fn multiselect(&mut self, prompt: &dyn Display, items: &[&str]) -> Result<Vec<String>> {
+        let mut multiselect = crate::multiselect(prompt);
+        for item in items {
+            multiselect = multiselect.item(item.to_string(), *item, "");
+        }
+        multiselect.interact()
+    }
+
+    fn spinner(&mut self) -> Spinner {
+        crate::spinner()
+    }
+
+    fn log_remark(&mut self, text: &dyn Display) -> io::Result<()> {
+        crate::log::remark(text)
+    }
+
+    fn log_info(&mut self, text: &dyn Display) -> io::Result<()> {
+        crate::log::info(text)
+    }
+
+    fn log_warning(&mut self, text: &dyn Display) -> io::Result<()> {
+        crate::log::warning(text)
+    }
+
+    fn log_error(&mut self, text: &dyn Display) -> io::Result<()> {
+        crate::log::error(text)
+    }
+
+    fn log_success(&mut self, text: &dyn Display) -> io::Result<()> {
+        crate::log::success(text)
+    }
+
+    fn log_step(&mut self, text: &dyn Display) -> io::Result<()> {
+        crate::log::step(text)
+    }
+}
+
+/// A queued answer or expected message for [`MockCli`].
+enum Expectation {
+    Confirm(bool),
+    Input(String),
+    Password(String),
+    Select(String),
+    MultiSelect(Vec<String>),
+}
+
+impl Expectation {
+    fn kind(&self) -> &'static str {
+        match self {
+            Expectation::Confirm(_) => "confirm",
+            Expectation::Input(_) => "input",
+            Expectation::Password(_) => "password",
+            Expectation::Select(_) => "select",
+            Expectation::MultiSelect(_) => "multiselect",
+        }
+    }
+}
+
+/// A scriptable [`Cli`] implementation for unit-testing prompt-driven code
+/// without a terminal.
+///
+/// Seed the answers a wizard should receive with the `expect_*` methods, in
+/// the order they'll be asked for, then drive the code under test against
+/// `&mut mock as &mut dyn Cli`-style usage (or generic `C: Cli`). Every
+/// emitted `intro`/`outro`/`note`/`log::*` message is recorded and can be
+/// inspected with [`MockCli::messages`]. Dropping a `MockCli` with queued
+/// answers still unconsumed panics, so a test that queues an answer the
+/// code never asks for fails loudly instead of passing silently.
+#[derive(Default)]
+pub struct MockCli {
+    expectations: VecDeque<Expectation>,
+    messages: Vec<String>,
+}
+
+impl MockCli {
+    /// Creates an empty mock with no queued answers.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queues the answer to the next [`Cli::confirm`] call.
+    pub fn expect_confirm(&mut self, answer: bool) -> &mut Self {
+        self.expectations.push_back(Expectation::Confirm(answer));
+        self
+    }
+
+    /// Queues the answer to the next [`Cli::input`] call.
+    pub fn expect_input(&mut self, answer: impl Display) -> &mut Self {
+        self.expectations
+            .push_back(Expectation::Input(answer.to_string()));
+        self
+    }
+
+    /// Queues the answer to the next [`Cli::password`] call.
+    pub fn expect_password(&mut self, answer: impl Display) -> &mut Self {
+        self.expectations
+            .push_back(Expectation::Password(answer.to_string()));
+        self
+    }
+
+    /// Queues the answer to the next [`Cli::select`] call.
+    pub fn expect_select(&mut self, answer: impl Display) -> &mut Self {
+        self.expectations
+            .push_back(Expectation::Select(answer.to_string()));
+        self
+    }
+
+    /// Queues the answer to the next [`Cli::multiselect`] call.
+    pub fn expect_multiselect(&mut self, answer: Vec<String>) -> &mut Self {
+        self.expectations
+            .push_back(Expectation::MultiSelect(answer));
+        self
+    }
+
+    /// Every message passed to `intro`/`outro`/`note`/`log::*` so far, in
+    /// order.
+    pub fn messages(&self) -> &[String] {
+        &self.messages
+    }
+
+    /// Asserts that every queued answer has been consumed. Also run
+    /// automatically on drop.
+    pub fn assert_exhausted(&self) {
+        assert!(
+            self.expectations.is_empty(),
+            "{} queued interaction(s) were never consumed: {}",
+            self.expectations.len(),
+            self.expectations
+                .iter()
+                .map(Expectation::kind)
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+    }
+
+    fn pop(&mut self, call: &str) -> Expectation {
+        self.expectations
+            .pop_front()
+            .unwrap_or_else(|| panic!("no queued answer for {call}()"))
+    }
+}
+
+impl Drop for MockCli {
+    fn drop(&mut self) {
+        if !std::thread::panicking() {
+            self.assert_exhausted();
+        }
+    }
+}
+
+impl Cli for MockCli {
+    fn intro(&mut self, title: &dyn Display) -> io::Result<()> {
+        self.messages.push(title.to_string());
+        Ok(())
+    }
+
+    fn outro(&mut self, message: &dyn Display) -> io::Result<()> {
+        self.messages.push(message.to_string());
+        Ok(())
+    }
+
+    fn note(&mut self, prompt: &dyn Display, message: &dyn Display) -> io::Result<()> {
+        self.messages.push(format!("{prompt}: {message}"));
+        Ok(())
+    }
+
+    fn confirm(&mut self, prompt: &dyn Display) -> Result<bool> {
+        let _ = prompt;
+        match self.pop("confirm") {
+            Expectation::Confirm(answer) => Ok(answer),
+            other => panic!(
+                "expected a confirm() call but the next queued answer was for {}()",
+                other.kind()
+            ),
+        }
+    }
+
+    fn input(&mut self, prompt: &dyn Display) -> Result<String> {
+        let _ = prompt;
+        match self.pop("input") {
+            Expectation::Input(answer) => Ok(answer),
+            other => panic!(
+                "expected an input() call but the next queued answer was for {}()",
+                other.kind()
+            ),
+        }
+    }
+
+    fn password(&mut self, prompt: &dyn Display) -> Result<String> {
+        let _ = prompt;
+        match self.pop("password") {
+            Expectation::Password(answer) => Ok(answer),
+            other => panic!(
+                "expected a password() call but the next queued answer was for {}()",
+                other.kind()
+            ),
+        }
+    }
+
+    fn select(&mut self, prompt: &dyn Display, items: &[&str]) -> Result<String> {
+        let _ = (prompt, items);
+        match self.pop("select") {
+            Expectation::Select(answer) => Ok(answer),
+            other => panic!(
+                "expected a select() call but the next queued answer was for {}()",
+                other.kind()
+            ),
+        }
+    }
+
+    fn multiselect(&mut self, prompt: &dyn Display, items: &[&str]) -> Result<Vec<String>> {
+        let _ = (prompt, items);
+        match self.pop("multiselect") {
+            Expectation::MultiSelect(answer) => Ok(answer),
+            other => panic!(
+                "expected a multiselect() call but the next queued answer was for {}()",
+                other.kind()
+            ),
+        }
+    }
+
+    fn spinner(&mut self) -> Spinner {
+        Spinner::default()
+    }
+
+    fn log_remark(&mut self, text: &dyn Display) -> io::Result<()> {
+        self.messages.push(text.to_string());
+        Ok(())
+    }
+
+    fn log_info(&mut self, text: &dyn Display) -> io::Result<()> {
+        self.messages.push(text.to_string());
+        Ok(())
+    }
+
+    fn log_warning(&mut self, text: &dyn Display) -> io::Result<()> {
+        self.messages.push(text.to_string());
+        Ok(())
+    }
+
+    fn log_error(&mut self, text: &dyn Display) -> io::Result<()> {
+        self.messages.push(text.to_string());
+        Ok(())
+    }
+
+    fn log_success(&mut self, text: &dyn Display) -> io::Result<()> {
+        self.messages.push(text.to_string());
+        Ok(())
+    }
+
+    fn log_step(&mut self, text: &dyn Display) -> io::Result<()> {
+        self.messages.push(text.to_string());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn returns_queued_answers_in_order() {
+        let mut mock = MockCli::new();
+        mock.expect_confirm(true);
+        mock.expect_input("Alice");
+        mock.expect_password("hunter2");
+        mock.expect_select("red");
+        mock.expect_multiselect(vec!["a".to_string(), "b".to_string()]);
+
+        assert!(mock.confirm(&"continue?").unwrap());
+        assert_eq!(mock.input(&"name?").unwrap(), "Alice");
+        assert_eq!(mock.password(&"secret?").unwrap(), "hunter2");
+        assert_eq!(mock.select(&"color?", &["red", "blue"]).unwrap(), "red");
+        assert_eq!(
+            mock.multiselect(&"letters?", &["a", "b"]).unwrap(),
+            vec!["a".to_string(), "b".to_string()]
+        );
+        mock.assert_exhausted();
+    }
+
+    #[test]
+    fn records_intro_outro_note_and_log_messages_in_order() {
+        let mut mock = MockCli::new();
+        mock.intro(&"start").unwrap();
+        mock.log_info(&"info").unwrap();
+        mock.note(&"heads up", &"details").unwrap();
+        mock.outro(&"done").unwrap();
+
+        assert_eq!(
+            mock.messages(),
+            ["start", "info", "heads up: details", "done"]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "no queued answer for confirm()")]
+    fn panics_when_no_answer_is_queued() {
+        let mut mock = MockCli::new();
+        let _ = mock.confirm(&"continue?");
+    }
+
+    #[test]
+    #[should_panic(expected = "expected a confirm() call but the next queued answer was for input()")]
+    fn panics_when_the_wrong_method_is_called() {
+        let mut mock = MockCli::new();
+        mock.expect_input("Alice");
+        let _ = mock.confirm(&"continue?");
+    }
+
+    #[test]
+    #[should_panic(expected = "queued interaction(s) were never consumed")]
+    fn assert_exhausted_panics_on_unconsumed_expectations() {
+        let mut mock = MockCli::new();
+        mock.expect_confirm(true);
+        mock.assert_exhausted();
+    }
+
+    #[test]
+    #[should_panic(expected = "queued interaction(s) were never consumed")]
+    fn drop_panics_on_unconsumed_expectations() {
+        let mut mock = MockCli::new();
+        mock.expect_confirm(true);
+    }
+}
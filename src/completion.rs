@@ -0,0 +1,16 @@
+/// A source of inline completions for [`Input`](crate::Input).
+///
+/// Mirrors dialoguer's `completion_with`: given the text typed so far,
+/// return the full replacement buffer to suggest, or `None` if there's
+/// nothing to suggest.
+pub trait Completion {
+    /// Returns the suggested completion for `input`, or `None` if there
+    /// isn't one.
+    fn get(&self, input: &str) -> Option<String>;
+}
+
+impl<F: Fn(&str) -> Option<String>> Completion for F {
+    fn get(&self, input: &str) -> Option<String> {
+        self(input)
+    }
+}
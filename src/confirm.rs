@@ -0,0 +1,107 @@
+use std::fmt::Display;
+
+use console::{style, Key, Term};
+
+use crate::error::{Error, Result};
+use crate::prompt::Prompt;
+use crate::theme::{ClackTheme, Theme, ThemeState};
+
+/// A yes/no prompt.
+///
+/// Constructed with [`confirm()`](crate::confirm) or [`Confirm::new`].
+pub struct Confirm {
+    prompt: String,
+    initial_value: bool,
+    on_cancel: Option<Box<dyn Fn()>>,
+}
+
+impl Confirm {
+    /// Creates a new confirm prompt with the given message.
+    pub fn new(prompt: impl Display) -> Self {
+        Self {
+            prompt: prompt.to_string(),
+            initial_value: true,
+            on_cancel: None,
+        }
+    }
+
+    /// Sets the value selected before the user answers.
+    pub fn initial_value(mut self, initial_value: bool) -> Self {
+        self.initial_value = initial_value;
+        self
+    }
+
+    /// Registers a callback run when the user cancels with `Esc`, before
+    /// [`Error::Cancelled`] is returned.
+    pub fn on_cancel(mut self, on_cancel: impl Fn() + 'static) -> Self {
+        self.on_cancel = Some(Box::new(on_cancel));
+        self
+    }
+
+    fn render(&self, value: bool, state: ThemeState) -> String {
+        let bullet = ClackTheme.state_symbol(state);
+
+        let answer = if state == ThemeState::Submit {
+            let label = if value { "Yes" } else { "No" };
+            format!("{}  {}\n", style("│").dim(), style(label).dim())
+        } else {
+            let yes = if value {
+                style("● Yes").cyan().to_string()
+            } else {
+                style("○ Yes").dim().to_string()
+            };
+            let no = if value {
+                style("○ No").dim().to_string()
+            } else {
+                style("● No").cyan().to_string()
+            };
+            format!("{}  {yes} / {no}\n", style("│").dim())
+        };
+
+        format!("{bullet}  {}\n{answer}", style(&self.prompt).bold())
+    }
+
+    /// Runs the prompt on the crate-wide default terminal, returning the
+    /// selected boolean once the user submits.
+    pub fn interact(&mut self) -> Result<bool> {
+        self.interact_on(&crate::prompt::default_term())
+    }
+
+    /// Runs the prompt on the given terminal, returning the selected
+    /// boolean once the user submits.
+    pub fn interact_on(&mut self, term: &Term) -> Result<bool> {
+        let mut value = self.initial_value;
+        let mut prompt = Prompt::on(term);
+
+        prompt.render(&self.render(value, ThemeState::Active))?;
+
+        let submitted = loop {
+            match prompt.read_key()? {
+                Key::ArrowLeft | Key::ArrowRight | Key::Tab => value = !value,
+                Key::Char('y') | Key::Char('Y') => {
+                    value = true;
+                    break true;
+                }
+                Key::Char('n') | Key::Char('N') => {
+                    value = false;
+                    break true;
+                }
+                Key::Enter => break true,
+                Key::Escape => break false,
+                _ => continue,
+            }
+            prompt.render(&self.render(value, ThemeState::Active))?;
+        };
+
+        if !submitted {
+            prompt.finish(&self.render(value, ThemeState::Cancel))?;
+            if let Some(on_cancel) = &self.on_cancel {
+                on_cancel();
+            }
+            return Err(Error::Cancelled);
+        }
+
+        prompt.finish(&self.render(value, ThemeState::Submit))?;
+        Ok(value)
+    }
+}
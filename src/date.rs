@@ -0,0 +1,188 @@
+use std::fmt::Display;
+
+use chrono::{Datelike, Days, Months, NaiveDate, Weekday};
+use console::{style, Key, Term};
+
+use crate::error::{Error, Result};
+use crate::prompt::Prompt;
+use crate::theme::{ClackTheme, Theme, ThemeState};
+
+/// A date-picker prompt: navigate a month grid with the arrow keys and
+/// submit with `Enter`.
+///
+/// `Left`/`Right` move by a day, `Up`/`Down` by a week, `PageUp`/`PageDown`
+/// by a month. Days outside [`min`](DatePicker::min)/[`max`](DatePicker::max)
+/// are dimmed and can't be selected.
+///
+/// Constructed with [`date()`](crate::date) or [`DatePicker::new`].
+pub struct DatePicker {
+    prompt: String,
+    value: NaiveDate,
+    min: Option<NaiveDate>,
+    max: Option<NaiveDate>,
+    week_start: Weekday,
+    on_cancel: Option<Box<dyn Fn()>>,
+}
+
+impl DatePicker {
+    /// Creates a new date picker with the given message, initially on
+    /// today's date.
+    pub fn new(prompt: impl Display) -> Self {
+        Self {
+            prompt: prompt.to_string(),
+            value: chrono::Local::now().date_naive(),
+            min: None,
+            max: None,
+            week_start: Weekday::Mon,
+            on_cancel: None,
+        }
+    }
+
+    /// Sets the date initially highlighted.
+    pub fn initial_value(mut self, value: NaiveDate) -> Self {
+        self.value = value;
+        self
+    }
+
+    /// Disables navigating to dates before `min`.
+    pub fn min(mut self, min: NaiveDate) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Disables navigating to dates after `max`.
+    pub fn max(mut self, max: NaiveDate) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Sets which weekday starts each row of the month grid (default Monday).
+    pub fn week_start(mut self, week_start: Weekday) -> Self {
+        self.week_start = week_start;
+        self
+    }
+
+    /// Registers a callback run when the user cancels with `Esc`, before
+    /// [`Error::Cancelled`] is returned.
+    pub fn on_cancel(mut self, on_cancel: impl Fn() + 'static) -> Self {
+        self.on_cancel = Some(Box::new(on_cancel));
+        self
+    }
+
+    fn in_range(&self, date: NaiveDate) -> bool {
+        self.min.is_none_or(|min| date >= min) && self.max.is_none_or(|max| date <= max)
+    }
+
+    fn move_to(&mut self, candidate: Option<NaiveDate>) {
+        if let Some(date) = candidate.filter(|date| self.in_range(*date)) {
+            self.value = date;
+        }
+    }
+
+    fn render(&self, state: ThemeState) -> String {
+        let bullet = ClackTheme.state_symbol(state);
+        let mut out = format!("{bullet}  {}\n", style(&self.prompt).bold());
+
+        if state == ThemeState::Submit {
+            out.push_str(&format!(
+                "{}  {}\n",
+                style("│").dim(),
+                style(self.value.format("%Y-%m-%d")).dim()
+            ));
+            return out;
+        }
+
+        out.push_str(&format!(
+            "{}  {}\n",
+            style("│").dim(),
+            style(self.value.format("%B %Y")).bold()
+        ));
+
+        let mut weekday = self.week_start;
+        let mut header = String::new();
+        for _ in 0..7 {
+            header.push_str(&format!("{:>3}", weekday.to_string()));
+            weekday = weekday.succ();
+        }
+        out.push_str(&format!("{}  {}\n", style("│").dim(), style(header).dim()));
+
+        let first_of_month = self.value.with_day(1).expect("day 1 always exists");
+        let lead = (7 + first_of_month.weekday().num_days_from_monday()
+            - self.week_start.num_days_from_monday())
+            % 7;
+        let mut cursor = first_of_month - Days::new(lead as u64);
+
+        for _ in 0..6 {
+            let mut row = String::new();
+            for _ in 0..7 {
+                let cell = format!("{:>2}", cursor.day());
+                let styled = if cursor.month() != self.value.month() || !self.in_range(cursor) {
+                    style(cell).dim().to_string()
+                } else if cursor == self.value {
+                    style(cell).cyan().reverse().to_string()
+                } else {
+                    cell
+                };
+                row.push_str(&format!("{styled} "));
+                cursor += chrono::TimeDelta::days(1);
+            }
+            out.push_str(&format!("{}  {row}\n", style("│").dim()));
+            if cursor.month() != self.value.month() && cursor > self.value {
+                break;
+            }
+        }
+
+        out
+    }
+
+    /// Runs the prompt on the crate-wide default terminal, returning the
+    /// selected date once the user submits.
+    pub fn interact(&mut self) -> Result<NaiveDate> {
+        self.interact_on(&crate::prompt::default_term())
+    }
+
+    /// Runs the prompt on the given terminal, returning the selected date
+    /// once the user submits.
+    pub fn interact_on(&mut self, term: &Term) -> Result<NaiveDate> {
+        if let Some(min) = self.min {
+            if self.value < min {
+                self.value = min;
+            }
+        }
+        if let Some(max) = self.max {
+            if self.value > max {
+                self.value = max;
+            }
+        }
+
+        let mut prompt = Prompt::on(term);
+
+        prompt.render(&self.render(ThemeState::Active))?;
+
+        let submitted = loop {
+            match prompt.read_key()? {
+                Key::ArrowLeft => self.move_to(self.value.checked_sub_days(Days::new(1))),
+                Key::ArrowRight => self.move_to(self.value.checked_add_days(Days::new(1))),
+                Key::ArrowUp => self.move_to(self.value.checked_sub_days(Days::new(7))),
+                Key::ArrowDown => self.move_to(self.value.checked_add_days(Days::new(7))),
+                Key::PageUp => self.move_to(self.value.checked_sub_months(Months::new(1))),
+                Key::PageDown => self.move_to(self.value.checked_add_months(Months::new(1))),
+                Key::Enter if self.in_range(self.value) => break true,
+                Key::Escape => break false,
+                _ => continue,
+            }
+            prompt.render(&self.render(ThemeState::Active))?;
+        };
+
+        if !submitted {
+            prompt.finish(&self.render(ThemeState::Cancel))?;
+            if let Some(on_cancel) = &self.on_cancel {
+                on_cancel();
+            }
+            return Err(Error::Cancelled);
+        }
+
+        prompt.finish(&self.render(ThemeState::Submit))?;
+        Ok(self.value)
+    }
+}
@@ -0,0 +1,64 @@
+use std::fmt;
+use std::io;
+
+/// The error returned by a prompt's `interact`/`interact_on`: either the
+/// user cancelled with `Esc`, or an I/O error occurred (a terminal failure,
+/// or an [`Input`](crate::Input) answer that couldn't be parsed).
+///
+/// Unlike a plain `io::Result`, this lets callers branch on cancellation
+/// (via [`IsCancel`]) without mistaking it for a real I/O failure.
+#[derive(Debug)]
+pub enum Error {
+    /// The user pressed `Esc` to back out of the prompt.
+    Cancelled,
+    /// Reading from or writing to the terminal failed, or the text submitted
+    /// to an [`Input`](crate::Input) couldn't be parsed into the target type
+    /// (reported with [`io::ErrorKind::InvalidInput`]).
+    Io(io::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Cancelled => write!(f, "cancelled by the user"),
+            Error::Io(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Cancelled => None,
+            Error::Io(err) => Some(err),
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Error::Io(err)
+    }
+}
+
+/// The `Result` alias returned by every prompt's `interact`/`interact_on`.
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Lets code distinguish "the user cancelled" from a real error or a
+/// success, without matching on [`Error`] directly.
+pub trait IsCancel {
+    /// Returns `true` if this represents the user cancelling the prompt.
+    fn is_cancel(&self) -> bool;
+}
+
+impl IsCancel for Error {
+    fn is_cancel(&self) -> bool {
+        matches!(self, Error::Cancelled)
+    }
+}
+
+impl<T> IsCancel for Result<T> {
+    fn is_cancel(&self) -> bool {
+        matches!(self, Err(Error::Cancelled))
+    }
+}
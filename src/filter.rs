@@ -0,0 +1,115 @@
+//! Subsequence-based fuzzy matching shared by the filterable prompts.
+//!
+//! A label matches a query when every query character appears in the label,
+//! case-insensitively and in order, with gaps allowed in between (so `"stp"`
+//! matches `"SetupScript"`). Survivors are ranked so that contiguous runs and
+//! matches starting at the beginning of a word score higher, and ties are
+//! broken with a normalized Levenshtein distance against the query.
+
+/// Scores `label` against `query`, or returns `None` if `query` isn't a
+/// (case-insensitive) subsequence of `label`. Higher scores rank first.
+pub(crate) fn fuzzy_score(label: &str, query: &str) -> Option<i64> {
+    if query.is_empty() {
+        return Some(0);
+    }
+
+    let label_lower = label.to_lowercase();
+    let query_lower = query.to_lowercase();
+
+    let label_chars: Vec<char> = label_lower.chars().collect();
+    let query_chars: Vec<char> = query_lower.chars().collect();
+
+    let mut label_index = 0;
+    let mut consecutive = 0i64;
+    let mut score = 0i64;
+
+    for &qc in &query_chars {
+        let offset = label_chars[label_index..].iter().position(|&lc| lc == qc)?;
+        label_index += offset;
+
+        score += 10;
+        if offset == 0 {
+            consecutive += 1;
+            score += consecutive * 5;
+        } else {
+            consecutive = 0;
+        }
+        if label_index == 0 || label_chars[label_index - 1] == ' ' {
+            score += 15;
+        }
+
+        label_index += 1;
+    }
+
+    let distance = strsim::levenshtein(&label_lower, &query_lower) as i64;
+    let max_len = label_chars.len().max(query_chars.len()).max(1) as i64;
+    let normalized = distance * 100 / max_len;
+
+    Some(score * 100 - normalized)
+}
+
+/// Returns the indices of `labels` that match `query`, best match first.
+pub(crate) fn filter_indices(labels: &[String], query: &str) -> Vec<usize> {
+    let mut scored: Vec<(usize, i64)> = labels
+        .iter()
+        .enumerate()
+        .filter_map(|(index, label)| fuzzy_score(label, query).map(|score| (index, score)))
+        .collect();
+    scored.sort_by_key(|&(_, score)| std::cmp::Reverse(score));
+    scored.into_iter().map(|(index, _)| index).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_query_matches_everything_with_score_zero() {
+        assert_eq!(fuzzy_score("anything", ""), Some(0));
+    }
+
+    #[test]
+    fn non_subsequence_does_not_match() {
+        assert_eq!(fuzzy_score("SetupScript", "xyz"), None);
+        assert_eq!(fuzzy_score("SetupScript", "tes"), None);
+    }
+
+    #[test]
+    fn case_insensitive_subsequence_matches() {
+        assert!(fuzzy_score("SetupScript", "stp").is_some());
+        assert!(fuzzy_score("SetupScript", "STP").is_some());
+    }
+
+    #[test]
+    fn contiguous_run_outscores_scattered_match() {
+        let contiguous = fuzzy_score("Setup", "set").unwrap();
+        let scattered = fuzzy_score("Stereotype", "set").unwrap();
+        assert!(contiguous > scattered);
+    }
+
+    #[test]
+    fn word_boundary_match_outscores_mid_word_match() {
+        let word_boundary = fuzzy_score("Open Settings", "set").unwrap();
+        let mid_word = fuzzy_score("Unsettling", "set").unwrap();
+        assert!(word_boundary > mid_word);
+    }
+
+    #[test]
+    fn filter_indices_hides_non_matches_and_keeps_best_match_first() {
+        let labels = ["Setup Script".to_string(), "Stereotype".to_string(), "Other".to_string()];
+        let indices = filter_indices(&labels, "set");
+        assert_eq!(indices, vec![0, 1]);
+    }
+
+    #[test]
+    fn filter_indices_no_match_returns_empty() {
+        let labels = ["Foo".to_string(), "Bar".to_string()];
+        assert_eq!(filter_indices(&labels, "zzz"), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn filter_indices_empty_query_keeps_original_order() {
+        let labels = ["Banana".to_string(), "Apple".to_string()];
+        assert_eq!(filter_indices(&labels, ""), vec![0, 1]);
+    }
+}
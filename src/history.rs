@@ -0,0 +1,13 @@
+/// Storage for previously submitted [`Input`](crate::Input) values, recalled
+/// with the `Up`/`Down` arrow keys.
+///
+/// Implementors decide how entries are kept (capped, deduplicated, ...);
+/// the prompt only calls `read`/`write`.
+pub trait History {
+    /// Returns the entry `pos` steps back from the most recent submission
+    /// (`pos == 0` is the most recent), or `None` once there's nothing older.
+    fn read(&self, pos: usize) -> Option<String>;
+
+    /// Records a newly submitted value.
+    fn write(&mut self, val: &str);
+}
@@ -0,0 +1,220 @@
+use std::fmt::Display;
+use std::io;
+use std::str::FromStr;
+
+use console::{style, Key, Term};
+
+use crate::completion::Completion;
+use crate::error::{Error, Result};
+use crate::history::History;
+use crate::prompt::Prompt;
+use crate::theme::{ClackTheme, Theme, ThemeState};
+use crate::validate::Validate;
+
+type Validator = Box<dyn Fn(&str) -> std::result::Result<(), String>>;
+
+/// A single-line text prompt that parses the answer into `T`.
+///
+/// Constructed with [`input()`](crate::input) or [`Input::new`].
+pub struct Input<'a> {
+    prompt: String,
+    placeholder: String,
+    default_value: String,
+    validate: Option<Validator>,
+    completion: Option<Box<dyn Completion>>,
+    history: Option<&'a mut dyn History>,
+    on_cancel: Option<Box<dyn Fn()>>,
+}
+
+impl<'a> Input<'a> {
+    /// Creates a new input prompt with the given message.
+    pub fn new(prompt: impl Display) -> Self {
+        Self {
+            prompt: prompt.to_string(),
+            placeholder: String::new(),
+            default_value: String::new(),
+            validate: None,
+            completion: None,
+            history: None,
+            on_cancel: None,
+        }
+    }
+
+    /// Sets a dimmed hint shown while the input is empty.
+    pub fn placeholder(mut self, placeholder: impl Display) -> Self {
+        self.placeholder = placeholder.to_string();
+        self
+    }
+
+    /// Sets the value used when the user submits an empty buffer.
+    pub fn default_input(mut self, default_value: impl Display) -> Self {
+        self.default_value = default_value.to_string();
+        self
+    }
+
+    /// Attaches a validation rule run on the raw text before parsing.
+    pub fn validate<V>(mut self, validator: V) -> Self
+    where
+        V: Validate<String> + 'static,
+        V::Err: Display,
+    {
+        self.validate = Some(Box::new(move |input: &str| {
+            validator
+                .validate(&input.to_string())
+                .map_err(|err| err.to_string())
+        }));
+        self
+    }
+
+    /// Attaches a completion source: pressing `Tab` (or `Right` at the end
+    /// of the line) replaces the buffer with its suggestion, if any.
+    pub fn completion(mut self, completion: impl Completion + 'static) -> Self {
+        self.completion = Some(Box::new(completion));
+        self
+    }
+
+    /// Attaches a history store: `Up`/`Down` recall previous submissions
+    /// into the buffer, and a successful submission is written back.
+    pub fn history_with<H: History>(mut self, history: &'a mut H) -> Self {
+        self.history = Some(history);
+        self
+    }
+
+    /// Registers a callback run when the user cancels with `Esc`, before
+    /// [`Error::Cancelled`] is returned.
+    pub fn on_cancel(mut self, on_cancel: impl Fn() + 'static) -> Self {
+        self.on_cancel = Some(Box::new(on_cancel));
+        self
+    }
+
+    fn render(&self, buffer: &str, state: ThemeState, error: Option<&str>) -> String {
+        let bullet = ClackTheme.state_symbol(state);
+        let value = if buffer.is_empty() && !self.placeholder.is_empty() {
+            style(&self.placeholder).dim().to_string()
+        } else {
+            buffer.to_string()
+        };
+
+        let mut out = format!("{bullet}  {}\n", style(&self.prompt).bold());
+        out.push_str(&format!("{}  {value}\n", style("│").dim()));
+        if let Some(error) = error {
+            out.push_str(&format!("{}  {}\n", style("└").red(), style(error).red()));
+        }
+        out
+    }
+
+    /// Runs the prompt on the crate-wide default terminal, returning the
+    /// parsed value once the user submits.
+    pub fn interact<T>(&mut self) -> Result<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.interact_on(&crate::prompt::default_term())
+    }
+
+    /// Runs the prompt on the given terminal, returning the parsed value
+    /// once the user submits.
+    pub fn interact_on<T>(&mut self, term: &Term) -> Result<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let mut buffer = String::new();
+        let mut prompt = Prompt::on(term);
+        let mut hist_pos = 0;
+        let mut stashed_buffer = String::new();
+
+        prompt.render(&self.render(&buffer, ThemeState::Active, None))?;
+
+        let submitted = loop {
+            match prompt.read_key()? {
+                Key::Char(c) => buffer.push(c),
+                Key::Backspace => {
+                    buffer.pop();
+                }
+                Key::ArrowUp => {
+                    let Some(history) = &self.history else {
+                        continue;
+                    };
+                    let Some(previous) = history.read(hist_pos) else {
+                        continue;
+                    };
+                    if hist_pos == 0 {
+                        stashed_buffer = buffer.clone();
+                    }
+                    hist_pos += 1;
+                    buffer = previous;
+                }
+                Key::ArrowDown => {
+                    if hist_pos == 0 {
+                        continue;
+                    }
+                    hist_pos -= 1;
+                    buffer = if hist_pos == 0 {
+                        stashed_buffer.clone()
+                    } else {
+                        self.history
+                            .as_ref()
+                            .and_then(|history| history.read(hist_pos - 1))
+                            .unwrap_or_default()
+                    };
+                }
+                Key::Tab | Key::ArrowRight => {
+                    let Some(completion) = &self.completion else {
+                        continue;
+                    };
+                    match completion.get(&buffer) {
+                        Some(suggestion) => buffer = suggestion,
+                        None => continue,
+                    }
+                }
+                Key::Enter => {
+                    let candidate = if buffer.is_empty() {
+                        &self.default_value
+                    } else {
+                        &buffer
+                    };
+                    if let Some(validate) = &self.validate {
+                        if let Err(message) = validate(candidate) {
+                            prompt.render(&self.render(&buffer, ThemeState::Error, Some(&message)))?;
+                            continue;
+                        }
+                    }
+                    break true;
+                }
+                Key::Escape => break false,
+                _ => continue,
+            }
+            prompt.render(&self.render(&buffer, ThemeState::Active, None))?;
+        };
+
+        if !submitted {
+            prompt.finish(&self.render(&buffer, ThemeState::Cancel, None))?;
+            if let Some(on_cancel) = &self.on_cancel {
+                on_cancel();
+            }
+            return Err(Error::Cancelled);
+        }
+
+        let value = if buffer.is_empty() {
+            self.default_value.clone()
+        } else {
+            buffer
+        };
+
+        prompt.finish(&self.render(&value, ThemeState::Submit, None))?;
+
+        let parsed = value
+            .parse::<T>()
+            .map_err(|err| Error::Io(io::Error::new(io::ErrorKind::InvalidInput, err.to_string())));
+
+        if parsed.is_ok() {
+            if let Some(history) = &mut self.history {
+                history.write(&value);
+            }
+        }
+
+        parsed
+    }
+}
@@ -47,10 +47,10 @@
 //! The input prompt accepts a single line of text trying to parse it into
 //! a target type.
 //!
-//! ```
+//! ```no_run
 //! use cliclack::input;
 //!
-//! # fn test() -> std::io::Result<()> {
+//! # fn test() -> cliclack::Result<()> {
 //! let number: String = input("What is the meaning of life?")
 //!     .placeholder("Not sure")
 //!     .validate(|input: &String| {
@@ -71,8 +71,8 @@
 //! The password prompt is similar to the input prompt, but it doesn't echo the
 //! actual characters.
 //!
-//! ```
-//! # fn test() -> std::io::Result<()> {
+//! ```no_run
+//! # fn test() -> cliclack::Result<()> {
 //! use cliclack::password;
 //!
 //! let password = password("Provide a password")
@@ -89,8 +89,8 @@
 //!
 //! '`Y`' and '`N`' keys are accepted as an immediate answer.
 //!
-//! ```
-//! # fn test() -> std::io::Result<()> {
+//! ```no_run
+//! # fn test() -> cliclack::Result<()> {
 //! use cliclack::confirm;
 //!
 //! let should_continue = confirm("Do you want to continue?").interact()?;
@@ -99,12 +99,29 @@
 //! # test().ok(); // Ignoring I/O runtime errors.
 //! ```
 //!
+//! ## Alert
+//!
+//! The alert prompt shows a message and waits for `Enter` to acknowledge it.
+//! Unlike [`confirm`], there's no yes/no to answer.
+//!
+//! ```no_run
+//! # fn test() -> cliclack::Result<()> {
+//! use cliclack::alert;
+//!
+//! alert("Something went wrong")
+//!     .message("Check the logs for details.")
+//!     .interact()?;
+//! # Ok(())
+//! # }
+//! # test().ok(); // Ignoring I/O runtime errors.
+//! ```
+//!
 //! ## Select
 //!
 //! The select prompt asks to choose one of the options from the list.
 //!
-//! ```
-//! # fn test() -> std::io::Result<()> {
+//! ```no_run
+//! # fn test() -> cliclack::Result<()> {
 //! use cliclack::select;
 //!
 //! let selected = select("Pick a project type")
@@ -122,8 +139,8 @@
 //! The multi-select prompt asks to choose one or more options from the list.
 //! The result is a vector of selected items.
 //!
-//! ```
-//! # fn test() -> std::io::Result<()> {
+//! ```no_run
+//! # fn test() -> cliclack::Result<()> {
 //! use cliclack::multiselect;
 //!
 //! let additional_tools = multiselect("Select additional tools.")
@@ -136,10 +153,24 @@
 //! # test().ok(); // Ignoring I/O runtime errors.
 //! ```
 //!
+//! ## Date
+//!
+//! The date prompt asks to pick a date from a navigable month grid.
+//!
+//! ```no_run
+//! # fn test() -> cliclack::Result<()> {
+//! use cliclack::date;
+//!
+//! let release_date = date("Pick a release date").interact()?;
+//! # Ok(())
+//! # }
+//! # test().ok(); // Ignoring I/O runtime errors.
+//! ```
+//!
 //! ## Spinner
 //!
 //! ```
-//! # fn test() -> std::io::Result<()> {
+//! # fn test() -> cliclack::Result<()> {
 //! use cliclack::spinner;
 //!
 //! let mut spinner = spinner();
@@ -151,7 +182,14 @@
 //! # test().ok(); // Ignoring I/O runtime errors.
 //! ```
 
+mod alert;
+mod cli;
+mod completion;
 mod confirm;
+mod date;
+mod error;
+mod filter;
+mod history;
 mod input;
 mod multiselect;
 mod password;
@@ -167,7 +205,14 @@ use std::io;
 
 use theme::{ClackTheme, Theme};
 
+pub use alert::Alert;
+pub use cli::{Cli, MockCli, RealCli};
+pub use completion::Completion;
 pub use confirm::Confirm;
+pub use date::DatePicker;
+pub use error::{Error, IsCancel, Result};
+pub use prompt::set_default_term;
+pub use history::History;
 pub use input::Input;
 pub use multiselect::MultiSelect;
 pub use password::Password;
@@ -176,7 +221,7 @@ pub use spinner::Spinner;
 pub use validate::Validate;
 
 fn term_write(line: String) -> io::Result<()> {
-    Term::stderr().write_str(&line)
+    prompt::default_term().write_str(&line)
 }
 
 /// Clears the terminal.
@@ -203,7 +248,7 @@ pub fn outro_cancel(message: impl Display) -> io::Result<()> {
 /// Constructs a new [`Input`] prompt.
 ///
 /// See [`Input`] for chainable methods.
-pub fn input(prompt: impl Display) -> Input {
+pub fn input<'a>(prompt: impl Display) -> Input<'a> {
     Input::new(prompt)
 }
 
@@ -235,6 +280,20 @@ pub fn confirm(prompt: impl Display) -> Confirm {
     Confirm::new(prompt)
 }
 
+/// Constructs a new [`Alert`] prompt.
+///
+/// See [`Alert`] for chainable methods.
+pub fn alert(prompt: impl Display) -> Alert {
+    Alert::new(prompt)
+}
+
+/// Constructs a new [`DatePicker`] prompt.
+///
+/// See [`DatePicker`] for chainable methods.
+pub fn date(prompt: impl Display) -> DatePicker {
+    DatePicker::new(prompt)
+}
+
 /// Constructs a new [`Spinner`] prompt.
 ///
 /// See [`Spinner`] for chainable methods.
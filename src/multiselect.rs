@@ -0,0 +1,201 @@
+use std::fmt::Display;
+
+use console::{style, Key, Term};
+
+use crate::error::{Error, Result};
+use crate::filter::filter_indices;
+use crate::prompt::Prompt;
+use crate::theme::{ClackTheme, Theme, ThemeState};
+
+/// A multiple-choice prompt: toggle any number of items with `Space` and
+/// submit with `Enter`. When [`filterable`](MultiSelect::filterable) is on
+/// and `Space` is typed into the filter query instead, `Tab` toggles the
+/// highlighted item.
+///
+/// Constructed with [`multiselect()`](crate::multiselect) or
+/// [`MultiSelect::new`].
+pub struct MultiSelect<T> {
+    prompt: String,
+    items: Vec<(T, String, String)>,
+    selected: Vec<bool>,
+    cursor: usize,
+    filterable: bool,
+    query: String,
+    on_cancel: Option<Box<dyn Fn()>>,
+}
+
+impl<T: Default + Clone + Eq> MultiSelect<T> {
+    /// Creates a new multiselect prompt with the given message.
+    pub fn new(prompt: impl Display) -> Self {
+        Self {
+            prompt: prompt.to_string(),
+            items: Vec::new(),
+            selected: Vec::new(),
+            cursor: 0,
+            filterable: false,
+            query: String::new(),
+            on_cancel: None,
+        }
+    }
+
+    /// Adds an item to the list: a `value`, a display `label`, and an
+    /// optional `hint` shown next to the active item.
+    pub fn item(mut self, value: T, label: impl Display, hint: impl Display) -> Self {
+        self.items.push((value, label.to_string(), hint.to_string()));
+        self.selected.push(false);
+        self
+    }
+
+    /// Marks the given value as selected before the user starts interacting.
+    pub fn initial_values(mut self, values: Vec<T>) -> Self {
+        for (index, (item, ..)) in self.items.iter().enumerate() {
+            if values.contains(item) {
+                self.selected[index] = true;
+            }
+        }
+        self
+    }
+
+    /// Enables type-to-filter: as the user types, the list narrows to items
+    /// whose label fuzzy-matches the typed query. Items already selected
+    /// stay selected even while filtered out of view.
+    pub fn filterable(mut self, filterable: bool) -> Self {
+        self.filterable = filterable;
+        self
+    }
+
+    /// Registers a callback run when the user cancels with `Esc`, before
+    /// [`Error::Cancelled`] is returned.
+    pub fn on_cancel(mut self, on_cancel: impl Fn() + 'static) -> Self {
+        self.on_cancel = Some(Box::new(on_cancel));
+        self
+    }
+
+    fn visible(&self) -> Vec<usize> {
+        if !self.filterable || self.query.is_empty() {
+            return (0..self.items.len()).collect();
+        }
+        let labels: Vec<String> = self.items.iter().map(|(_, label, _)| label.clone()).collect();
+        filter_indices(&labels, &self.query)
+    }
+
+    fn render(&self, visible: &[usize], state: ThemeState) -> String {
+        let bullet = ClackTheme.state_symbol(state);
+        let mut out = format!("{bullet}  {}\n", style(&self.prompt).bold());
+
+        if state == ThemeState::Submit {
+            let labels: Vec<_> = self
+                .items
+                .iter()
+                .zip(&self.selected)
+                .filter(|(_, selected)| **selected)
+                .map(|((_, label, _), _)| label.clone())
+                .collect();
+            out.push_str(&format!(
+                "{}  {}\n",
+                style("│").dim(),
+                style(labels.join(", ")).dim()
+            ));
+            return out;
+        }
+
+        if self.filterable {
+            let query = if self.query.is_empty() {
+                style("Type to filter...").dim().to_string()
+            } else {
+                self.query.clone()
+            };
+            out.push_str(&format!("{}  {query}\n", style("│").dim()));
+        }
+
+        if visible.is_empty() {
+            out.push_str(&format!("{}  {}\n", style("│").dim(), style("No matches").dim()));
+            return out;
+        }
+
+        for (position, &index) in visible.iter().enumerate() {
+            let (_, label, hint) = &self.items[index];
+            let active = position == self.cursor;
+            let checkbox = if self.selected[index] { "◼" } else { "◻" };
+            let hint = if active && !hint.is_empty() {
+                format!(" {}", style(format!("({hint})")).dim())
+            } else {
+                String::new()
+            };
+            let line = if active {
+                format!("{} {label}{hint}", style(checkbox).cyan())
+            } else if self.selected[index] {
+                format!("{} {label}", style(checkbox).cyan())
+            } else {
+                format!("{} {}", style(checkbox).dim(), style(label).dim())
+            };
+            out.push_str(&format!("{}  {line}\n", style("│").dim()));
+        }
+
+        out
+    }
+
+    /// Runs the prompt on the crate-wide default terminal, returning the
+    /// selected values once the user submits.
+    pub fn interact(&mut self) -> Result<Vec<T>> {
+        self.interact_on(&crate::prompt::default_term())
+    }
+
+    /// Runs the prompt on the given terminal, returning the selected
+    /// values once the user submits.
+    pub fn interact_on(&mut self, term: &Term) -> Result<Vec<T>> {
+        let mut prompt = Prompt::on(term);
+        let mut visible = self.visible();
+
+        prompt.render(&self.render(&visible, ThemeState::Active))?;
+
+        let submitted = loop {
+            match prompt.read_key()? {
+                Key::ArrowUp if self.cursor > 0 => self.cursor -= 1,
+                Key::ArrowUp => self.cursor = visible.len().saturating_sub(1),
+                Key::ArrowDown if self.cursor + 1 < visible.len() => self.cursor += 1,
+                Key::ArrowDown => self.cursor = 0,
+                Key::Char(' ') if !self.filterable && !visible.is_empty() => {
+                    let index = visible[self.cursor];
+                    self.selected[index] = !self.selected[index];
+                }
+                Key::Tab if !visible.is_empty() => {
+                    let index = visible[self.cursor];
+                    self.selected[index] = !self.selected[index];
+                }
+                Key::Char(c) if self.filterable => {
+                    self.query.push(c);
+                    visible = self.visible();
+                    self.cursor = self.cursor.min(visible.len().saturating_sub(1));
+                }
+                Key::Backspace if self.filterable && !self.query.is_empty() => {
+                    self.query.pop();
+                    visible = self.visible();
+                    self.cursor = self.cursor.min(visible.len().saturating_sub(1));
+                }
+                Key::Enter => break true,
+                Key::Escape => break false,
+                _ => continue,
+            }
+            prompt.render(&self.render(&visible, ThemeState::Active))?;
+        };
+
+        if !submitted {
+            prompt.finish(&self.render(&visible, ThemeState::Cancel))?;
+            if let Some(on_cancel) = &self.on_cancel {
+                on_cancel();
+            }
+            return Err(Error::Cancelled);
+        }
+
+        prompt.finish(&self.render(&visible, ThemeState::Submit))?;
+
+        Ok(self
+            .items
+            .iter()
+            .zip(&self.selected)
+            .filter(|(_, selected)| **selected)
+            .map(|((value, ..), _)| value.clone())
+            .collect())
+    }
+}
@@ -0,0 +1,118 @@
+use std::fmt::Display;
+
+use console::{style, Key, Term};
+
+use crate::error::{Error, Result};
+use crate::prompt::Prompt;
+use crate::theme::{ClackTheme, Theme, ThemeState};
+use crate::validate::Validate;
+
+type Validator = Box<dyn Fn(&str) -> std::result::Result<(), String>>;
+
+/// A single-line text prompt whose input is masked as it's typed.
+///
+/// Constructed with [`password()`](crate::password) or [`Password::new`].
+pub struct Password {
+    prompt: String,
+    mask: char,
+    validate: Option<Validator>,
+    on_cancel: Option<Box<dyn Fn()>>,
+}
+
+impl Password {
+    /// Creates a new password prompt with the given message.
+    pub fn new(prompt: impl Display) -> Self {
+        Self {
+            prompt: prompt.to_string(),
+            mask: '▪',
+            validate: None,
+            on_cancel: None,
+        }
+    }
+
+    /// Sets the character drawn in place of each typed character.
+    pub fn mask(mut self, mask: char) -> Self {
+        self.mask = mask;
+        self
+    }
+
+    /// Attaches a validation rule run on the raw text before it's returned.
+    pub fn validate<V>(mut self, validator: V) -> Self
+    where
+        V: Validate<String> + 'static,
+        V::Err: Display,
+    {
+        self.validate = Some(Box::new(move |input: &str| {
+            validator
+                .validate(&input.to_string())
+                .map_err(|err| err.to_string())
+        }));
+        self
+    }
+
+    /// Registers a callback run when the user cancels with `Esc`, before
+    /// [`Error::Cancelled`] is returned.
+    pub fn on_cancel(mut self, on_cancel: impl Fn() + 'static) -> Self {
+        self.on_cancel = Some(Box::new(on_cancel));
+        self
+    }
+
+    fn render(&self, buffer: &str, state: ThemeState, error: Option<&str>) -> String {
+        let bullet = ClackTheme.state_symbol(state);
+        let masked: String = self.mask.to_string().repeat(buffer.chars().count());
+
+        let mut out = format!("{bullet}  {}\n", style(&self.prompt).bold());
+        out.push_str(&format!("{}  {masked}\n", style("│").dim()));
+        if let Some(error) = error {
+            out.push_str(&format!("{}  {}\n", style("└").red(), style(error).red()));
+        }
+        out
+    }
+
+    /// Runs the prompt on the crate-wide default terminal, returning the
+    /// raw text once the user submits.
+    pub fn interact(&mut self) -> Result<String> {
+        self.interact_on(&crate::prompt::default_term())
+    }
+
+    /// Runs the prompt on the given terminal, returning the raw text once
+    /// the user submits.
+    pub fn interact_on(&mut self, term: &Term) -> Result<String> {
+        let mut buffer = String::new();
+        let mut prompt = Prompt::on(term);
+
+        prompt.render(&self.render(&buffer, ThemeState::Active, None))?;
+
+        let submitted = loop {
+            match prompt.read_key()? {
+                Key::Char(c) => buffer.push(c),
+                Key::Backspace => {
+                    buffer.pop();
+                }
+                Key::Enter => {
+                    if let Some(validate) = &self.validate {
+                        if let Err(message) = validate(&buffer) {
+                            prompt.render(&self.render(&buffer, ThemeState::Error, Some(&message)))?;
+                            continue;
+                        }
+                    }
+                    break true;
+                }
+                Key::Escape => break false,
+                _ => continue,
+            }
+            prompt.render(&self.render(&buffer, ThemeState::Active, None))?;
+        };
+
+        if !submitted {
+            prompt.finish(&self.render(&buffer, ThemeState::Cancel, None))?;
+            if let Some(on_cancel) = &self.on_cancel {
+                on_cancel();
+            }
+            return Err(Error::Cancelled);
+        }
+
+        prompt.finish(&self.render(&buffer, ThemeState::Submit, None))?;
+        Ok(buffer)
+    }
+}
@@ -0,0 +1,64 @@
+use std::io;
+use std::sync::{LazyLock, Mutex};
+
+use console::{Key, Term};
+
+static DEFAULT_TERM: LazyLock<Mutex<Term>> = LazyLock::new(|| Mutex::new(Term::stderr()));
+
+/// Overrides the terminal that `intro`/`outro`/`note`/`log::*` and every
+/// prompt's plain `interact()` write to (default: `Term::stderr()`).
+///
+/// A single prompt can still opt out of this default with its own
+/// `interact_on`.
+pub fn set_default_term(term: Term) {
+    *DEFAULT_TERM.lock().unwrap() = term;
+}
+
+/// Returns a clone of the current default terminal target.
+pub(crate) fn default_term() -> Term {
+    DEFAULT_TERM.lock().unwrap().clone()
+}
+
+/// Shared rendering/input-reading plumbing used by every interactive prompt.
+///
+/// Each prompt type (`Input`, `Confirm`, `Select`, ...) keeps one of these
+/// around for the duration of `interact()`/`interact_on()`: it owns the
+/// terminal handle and tracks how many lines were last drawn so they can be
+/// cleared before the next redraw. Key handling, including `Esc` to cancel,
+/// is left to each prompt.
+pub(crate) struct Prompt {
+    term: Term,
+    last_height: usize,
+}
+
+impl Prompt {
+    /// Creates a prompt writing to the given terminal target.
+    pub(crate) fn on(term: &Term) -> Self {
+        Self {
+            term: term.clone(),
+            last_height: 0,
+        }
+    }
+
+    /// Reads the next key press from the terminal.
+    pub(crate) fn read_key(&self) -> io::Result<Key> {
+        self.term.read_key()
+    }
+
+    /// Clears the previously rendered frame and writes a new one.
+    pub(crate) fn render(&mut self, frame: &str) -> io::Result<()> {
+        self.term.clear_last_lines(self.last_height)?;
+        self.term.write_str(frame)?;
+        self.last_height = frame.matches('\n').count();
+        Ok(())
+    }
+
+    /// Replaces the last rendered frame with the final output and stops
+    /// repainting (used once the prompt has been submitted or cancelled).
+    pub(crate) fn finish(&mut self, frame: &str) -> io::Result<()> {
+        self.term.clear_last_lines(self.last_height)?;
+        self.term.write_str(frame)?;
+        self.last_height = 0;
+        Ok(())
+    }
+}
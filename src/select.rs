@@ -0,0 +1,163 @@
+use std::fmt::Display;
+
+use console::{style, Key, Term};
+
+use crate::error::{Error, Result};
+use crate::filter::filter_indices;
+use crate::prompt::Prompt;
+use crate::theme::{ClackTheme, Theme, ThemeState};
+
+/// A single-choice prompt: pick one item from a list with the arrow keys.
+///
+/// Constructed with [`select()`](crate::select) or [`Select::new`].
+pub struct Select<T> {
+    prompt: String,
+    items: Vec<(T, String, String)>,
+    cursor: usize,
+    filterable: bool,
+    query: String,
+    on_cancel: Option<Box<dyn Fn()>>,
+}
+
+impl<T: Default + Clone + Eq> Select<T> {
+    /// Creates a new select prompt with the given message.
+    pub fn new(prompt: impl Display) -> Self {
+        Self {
+            prompt: prompt.to_string(),
+            items: Vec::new(),
+            cursor: 0,
+            filterable: false,
+            query: String::new(),
+            on_cancel: None,
+        }
+    }
+
+    /// Adds an item to the list: a `value`, a display `label`, and an
+    /// optional `hint` shown next to the active item.
+    pub fn item(mut self, value: T, label: impl Display, hint: impl Display) -> Self {
+        self.items.push((value, label.to_string(), hint.to_string()));
+        self
+    }
+
+    /// Sets the value initially highlighted.
+    pub fn initial_value(mut self, value: T) -> Self {
+        if let Some(index) = self.items.iter().position(|(item, ..)| *item == value) {
+            self.cursor = index;
+        }
+        self
+    }
+
+    /// Enables type-to-filter: as the user types, the list narrows to items
+    /// whose label fuzzy-matches the typed query.
+    pub fn filterable(mut self, filterable: bool) -> Self {
+        self.filterable = filterable;
+        self
+    }
+
+    /// Registers a callback run when the user cancels with `Esc`, before
+    /// [`Error::Cancelled`] is returned.
+    pub fn on_cancel(mut self, on_cancel: impl Fn() + 'static) -> Self {
+        self.on_cancel = Some(Box::new(on_cancel));
+        self
+    }
+
+    fn visible(&self) -> Vec<usize> {
+        if !self.filterable || self.query.is_empty() {
+            return (0..self.items.len()).collect();
+        }
+        let labels: Vec<String> = self.items.iter().map(|(_, label, _)| label.clone()).collect();
+        filter_indices(&labels, &self.query)
+    }
+
+    fn render(&self, visible: &[usize], state: ThemeState) -> String {
+        let bullet = ClackTheme.state_symbol(state);
+        let mut out = format!("{bullet}  {}\n", style(&self.prompt).bold());
+
+        if state == ThemeState::Submit {
+            let (_, label, _) = &self.items[visible[self.cursor]];
+            out.push_str(&format!("{}  {}\n", style("│").dim(), style(label).dim()));
+            return out;
+        }
+
+        if self.filterable {
+            let query = if self.query.is_empty() {
+                style("Type to filter...").dim().to_string()
+            } else {
+                self.query.clone()
+            };
+            out.push_str(&format!("{}  {query}\n", style("│").dim()));
+        }
+
+        if visible.is_empty() {
+            out.push_str(&format!("{}  {}\n", style("│").dim(), style("No matches").dim()));
+            return out;
+        }
+
+        for (position, &index) in visible.iter().enumerate() {
+            let (_, label, hint) = &self.items[index];
+            let active = position == self.cursor;
+            let line = if active {
+                let hint = if hint.is_empty() {
+                    String::new()
+                } else {
+                    format!(" {}", style(format!("({hint})")).dim())
+                };
+                format!("{} {label}{hint}", style("●").cyan())
+            } else {
+                format!("{} {}", style("○").dim(), style(label).dim())
+            };
+            out.push_str(&format!("{}  {line}\n", style("│").dim()));
+        }
+
+        out
+    }
+
+    /// Runs the prompt on the crate-wide default terminal, returning the
+    /// selected value once the user submits.
+    pub fn interact(&mut self) -> Result<T> {
+        self.interact_on(&crate::prompt::default_term())
+    }
+
+    /// Runs the prompt on the given terminal, returning the selected value
+    /// once the user submits.
+    pub fn interact_on(&mut self, term: &Term) -> Result<T> {
+        let mut prompt = Prompt::on(term);
+        let mut visible = self.visible();
+
+        prompt.render(&self.render(&visible, ThemeState::Active))?;
+
+        let submitted = loop {
+            match prompt.read_key()? {
+                Key::ArrowUp if self.cursor > 0 => self.cursor -= 1,
+                Key::ArrowUp => self.cursor = visible.len().saturating_sub(1),
+                Key::ArrowDown if self.cursor + 1 < visible.len() => self.cursor += 1,
+                Key::ArrowDown => self.cursor = 0,
+                Key::Char(c) if self.filterable => {
+                    self.query.push(c);
+                    visible = self.visible();
+                    self.cursor = self.cursor.min(visible.len().saturating_sub(1));
+                }
+                Key::Backspace if self.filterable && !self.query.is_empty() => {
+                    self.query.pop();
+                    visible = self.visible();
+                    self.cursor = self.cursor.min(visible.len().saturating_sub(1));
+                }
+                Key::Enter if !visible.is_empty() => break true,
+                Key::Escape => break false,
+                _ => continue,
+            }
+            prompt.render(&self.render(&visible, ThemeState::Active))?;
+        };
+
+        if !submitted {
+            prompt.finish(&self.render(&visible, ThemeState::Cancel))?;
+            if let Some(on_cancel) = &self.on_cancel {
+                on_cancel();
+            }
+            return Err(Error::Cancelled);
+        }
+
+        prompt.finish(&self.render(&visible, ThemeState::Submit))?;
+        Ok(self.items[visible[self.cursor]].0.clone())
+    }
+}
@@ -0,0 +1,76 @@
+use std::fmt::Display;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use console::style;
+
+use crate::prompt::default_term;
+
+const FRAMES: &[&str] = &["◒", "◐", "◓", "◑"];
+const INTERVAL: Duration = Duration::from_millis(100);
+
+/// An indeterminate progress indicator for long-running steps.
+///
+/// Constructed with [`spinner()`](crate::spinner).
+#[derive(Default)]
+pub struct Spinner {
+    running: Option<(Arc<AtomicBool>, JoinHandle<()>)>,
+    message: Arc<Mutex<String>>,
+}
+
+impl Spinner {
+    /// Starts the animation, showing `message` next to the spinning frame.
+    pub fn start(&mut self, message: impl Display) {
+        self.stop_animation();
+
+        *self.message.lock().unwrap() = message.to_string();
+        let message = Arc::clone(&self.message);
+        let stop = Arc::new(AtomicBool::new(false));
+        let stop_loop = Arc::clone(&stop);
+
+        let handle = thread::spawn(move || {
+            let term = default_term();
+            let mut frame = 0;
+            while !stop_loop.load(Ordering::Relaxed) {
+                let _ = term.clear_line();
+                let _ = term.write_str(&format!(
+                    "{}  {}",
+                    style(FRAMES[frame % FRAMES.len()]).magenta(),
+                    message.lock().unwrap()
+                ));
+                frame = frame.wrapping_add(1);
+                thread::sleep(INTERVAL);
+            }
+        });
+
+        self.running = Some((stop, handle));
+    }
+
+    /// Updates the message shown while the spinner is running.
+    pub fn set_message(&self, message: impl Display) {
+        *self.message.lock().unwrap() = message.to_string();
+    }
+
+    /// Stops the animation and replaces it with a final `message`.
+    pub fn stop(&mut self, message: impl Display) {
+        self.stop_animation();
+        let term = default_term();
+        let _ = term.clear_line();
+        let _ = term.write_line(&format!("{}  {message}", style("✔").green()));
+    }
+
+    fn stop_animation(&mut self) {
+        if let Some((stop, handle)) = self.running.take() {
+            stop.store(true, Ordering::Relaxed);
+            let _ = handle.join();
+        }
+    }
+}
+
+impl Drop for Spinner {
+    fn drop(&mut self) {
+        self.stop_animation();
+    }
+}
@@ -0,0 +1,107 @@
+use console::style;
+
+/// Where a prompt currently stands; controls which symbol/color is used to
+/// draw its leading bullet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ThemeState {
+    Active,
+    Submit,
+    Cancel,
+    Error,
+}
+
+/// Formatting for every piece of chrome cliclack draws: intros/outros,
+/// notes, log lines, and the individual prompts.
+///
+/// This is split out from the prompt implementations so the whole visual
+/// language (colors, symbols, indentation) lives in one place and can be
+/// swapped out wholesale.
+pub(crate) trait Theme {
+    fn state_symbol(&self, state: ThemeState) -> String {
+        match state {
+            ThemeState::Active => style("◆").cyan().to_string(),
+            ThemeState::Submit => style("◇").dim().to_string(),
+            ThemeState::Cancel => style("■").red().to_string(),
+            ThemeState::Error => style("▲").yellow().to_string(),
+        }
+    }
+
+    fn remark_symbol(&self) -> String {
+        style("◆").magenta().to_string()
+    }
+
+    fn info_symbol(&self) -> String {
+        style("●").blue().to_string()
+    }
+
+    fn warning_symbol(&self) -> String {
+        style("▲").yellow().to_string()
+    }
+
+    fn error_symbol(&self) -> String {
+        style("■").red().to_string()
+    }
+
+    fn active_symbol(&self) -> String {
+        style("◆").green().to_string()
+    }
+
+    fn submit_symbol(&self) -> String {
+        style("✔").green().to_string()
+    }
+
+    fn format_intro(&self, title: &str) -> String {
+        format!("{}  {}\n", style("┌").dim(), style(title).bold())
+    }
+
+    fn format_outro(&self, message: &str) -> String {
+        format!(
+            "{}\n{}  {}\n",
+            style("│").dim(),
+            style("└").dim(),
+            message
+        )
+    }
+
+    fn format_cancel(&self, message: &str) -> String {
+        format!(
+            "{}\n{}  {}\n",
+            style("│").dim(),
+            style("└").red(),
+            style(message).red()
+        )
+    }
+
+    fn format_note(&self, prompt: &str, message: &str) -> String {
+        let mut out = format!("{}  {}\n", self.remark_symbol(), style(prompt).bold());
+        for line in message.lines() {
+            out.push_str(&format!("{}  {line}\n", style("│").dim()));
+        }
+        out
+    }
+
+    fn format_log(&self, text: &str, symbol: &str) -> String {
+        format!("{symbol}  {text}\n")
+    }
+
+    fn format_alert(&self, prompt: &str, message: &str, state: ThemeState) -> String {
+        let bullet = self.state_symbol(state);
+        let mut out = format!("{bullet}  {}\n", style(prompt).bold());
+        for line in message.lines() {
+            out.push_str(&format!("{}  {line}\n", style("│").dim()));
+        }
+        if state == ThemeState::Active {
+            out.push_str(&format!(
+                "{}  {}\n",
+                style("│").dim(),
+                style("Press Enter to continue…").dim()
+            ));
+        }
+        out
+    }
+}
+
+/// The default cliclack theme.
+pub(crate) struct ClackTheme;
+
+impl Theme for ClackTheme {}
@@ -0,0 +1,21 @@
+use std::fmt::Display;
+
+/// A validation rule for prompt input.
+///
+/// Implemented for any `Fn(&T) -> Result<(), E>` closure, so most prompts
+/// accept a plain closure via [`.validate()`](crate::Input::validate).
+pub trait Validate<T: ?Sized> {
+    /// Error type that's returned on validation failure.
+    type Err: Display;
+
+    /// Validates the input, returning `Err` with a message to display when invalid.
+    fn validate(&self, input: &T) -> Result<(), Self::Err>;
+}
+
+impl<T: ?Sized, E: Display, F: Fn(&T) -> Result<(), E>> Validate<T> for F {
+    type Err = E;
+
+    fn validate(&self, input: &T) -> Result<(), Self::Err> {
+        self(input)
+    }
+}